@@ -0,0 +1,85 @@
+//! Human-facing diagnostics
+//!
+//! The [`yaml`](crate::yaml) module records a [`YamlSource`](crate::yaml) for
+//! every input it parses, and [`marked_yaml`] spans carry the source id back.
+//! This module turns a span into an annotated `file:line:col` snippet with a
+//! caret underneath the offending column, in the style of rustc's diagnostics.
+
+use std::fmt::Write;
+use std::io;
+
+use marked_yaml::Span;
+
+use crate::resources;
+use crate::yaml::{resolve_span, ResolvedLocation, ResolvedOrigin};
+
+/// Render an annotated caret snippet for the given span
+///
+/// Returns `Ok(None)` when the span cannot be resolved to a location (for
+/// instance when it carries no start marker, or its source is unknown).
+pub fn render_span(span: &Span) -> io::Result<Option<String>> {
+    match resolve_span(span) {
+        Some(loc) => render_location(&loc).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Render an annotated caret snippet for an already-resolved location
+///
+/// The originating file or resource is read afresh so the rendered snippet
+/// always reflects the source the location points at.
+pub fn render_location(loc: &ResolvedLocation) -> io::Result<String> {
+    let source = origin_text(&loc.origin)?;
+    Ok(annotate(&source, loc))
+}
+
+/// Read the text of a resolved origin, from disk or the embedded resources
+fn origin_text(origin: &ResolvedOrigin) -> io::Result<String> {
+    match origin {
+        ResolvedOrigin::File(path) => std::fs::read_to_string(path),
+        ResolvedOrigin::Slide(path) => std::fs::read_to_string(path),
+        ResolvedOrigin::Resource(name) => {
+            let (_, content) = resources::get(name)?;
+            String::from_utf8(content.into_owned())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        }
+    }
+}
+
+/// Build the caret snippet for `loc` against the given source text
+fn annotate(source: &str, loc: &ResolvedLocation) -> String {
+    let line_text = source.lines().nth(loc.line.saturating_sub(1)).unwrap_or("");
+    let gutter = loc.line.to_string();
+    let pad = " ".repeat(gutter.len());
+    let caret = " ".repeat(loc.column.saturating_sub(1));
+
+    let mut out = String::new();
+    // The unwraps are writing to a String, which is infallible.
+    writeln!(out, "{pad}--> {}", loc).unwrap();
+    writeln!(out, "{pad} |").unwrap();
+    writeln!(out, "{gutter} | {line_text}").unwrap();
+    write!(out, "{pad} | {caret}^").unwrap();
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::yaml::{ResolvedLocation, ResolvedOrigin};
+
+    #[test]
+    fn caret_sits_under_the_1_indexed_column() {
+        let loc = ResolvedLocation {
+            origin: ResolvedOrigin::Resource("test".into()),
+            line: 2,
+            column: 8,
+        };
+        let out = annotate("title: One\nfoo: Bar", &loc);
+
+        // The snippet shows the 1-indexed line's text in its gutter.
+        assert!(out.contains("2 | foo: Bar"), "got: {out}");
+        // The caret line pads with (column - 1) spaces before the `^`.
+        let expected = format!("{} | {}^", " ", " ".repeat(7));
+        assert_eq!(out.lines().last().unwrap(), expected, "got: {out}");
+    }
+}
@@ -18,3 +18,6 @@ pub use slides::SlideFile;
 
 #[doc(inline)]
 pub use slides::SlideContent;
+
+#[doc(inline)]
+pub use slides::ResolvedSlide;
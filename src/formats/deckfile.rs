@@ -9,6 +9,7 @@ use tera::{Map, Value};
 
 use crate::yaml::{from_file, YAMLLoadError};
 
+use super::slides::merge_spanned_list;
 use super::SlideMetadata;
 
 #[derive(Deserialize)]
@@ -32,7 +33,7 @@ pub struct DeckFile {
     tree_sitter_highlight: Option<HashMap<String, String>>,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 /// The markdown configuration for the deck
 pub struct Markdown {
@@ -41,7 +42,7 @@ pub struct Markdown {
     pub code_block_focus: Option<Spanned<String>>,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 /// The blockquote markdown configuration for the deck
 pub struct MarkdownBlockQuote {
@@ -59,8 +60,58 @@ impl DeckFile {
     }
 
     /// Perform merges where `other` is considered the default values
-    pub fn merge_from(&mut self, _other: &DeckFile) {
-        todo!()
+    ///
+    /// This composes `self` on top of `other` so that a concrete deck can layer
+    /// over a base/theme deck (for example via an `extends:` relationship).  The
+    /// precedence is fixed and deterministic:
+    ///
+    /// * Optional fields (`markdown`, `context`, `meta`) are taken from `other`
+    ///   when `self` lacks them.  When both have one they are merged rather
+    ///   than replaced wholesale: `context` and `markdown` key-by-key, and
+    ///   `meta` field by field (its scalars, including `ratio`, keep `self`'s
+    ///   value where set; its `inherit`, `require` and `deny` lists combine
+    ///   like the list fields below), so a base deck can supply individual
+    ///   defaults without clobbering the overriding deck's keys.
+    /// * List fields (`styles`, `scripts`, and `template-path`) are combined
+    ///   with `other`'s entries first and `self`'s appended, de-duplicated by
+    ///   string value while preserving the `Spanned` provenance of the winning
+    ///   (first-seen) entry.
+    /// * `tree-sitter-highlight` is a map and is merged key-by-key, keeping
+    ///   `self`'s value for a key both decks set.
+    ///
+    /// `slides` are left untouched: they belong to the concrete deck alone.
+    pub fn merge_from(&mut self, other: &DeckFile) {
+        match (&mut self.markdown, &other.markdown) {
+            (Some(mine), Some(theirs)) => mine.merge_from(theirs),
+            (slot @ None, Some(theirs)) => *slot = Some(theirs.clone()),
+            _ => {}
+        }
+
+        match (&mut self.context, &other.context) {
+            (Some(mine), Some(theirs)) => merge_context(mine, theirs),
+            (slot @ None, Some(theirs)) => *slot = Some(theirs.clone()),
+            _ => {}
+        }
+
+        match (&mut self.meta, &other.meta) {
+            (Some(mine), Some(theirs)) => mine.merge_from(theirs),
+            (slot @ None, Some(theirs)) => *slot = Some(theirs.clone()),
+            _ => {}
+        }
+
+        merge_spanned_list(&mut self.styles, &other.styles);
+        merge_spanned_list(&mut self.scripts, &other.scripts);
+        merge_spanned_list(&mut self.template_path, &other.template_path);
+
+        match (&mut self.tree_sitter_highlight, &other.tree_sitter_highlight) {
+            (Some(mine), Some(theirs)) => {
+                for (k, v) in theirs {
+                    mine.entry(k.clone()).or_insert_with(|| v.clone());
+                }
+            }
+            (slot @ None, Some(theirs)) => *slot = Some(theirs.clone()),
+            _ => {}
+        }
     }
 
     /// The style resources
@@ -110,3 +161,91 @@ impl DeckFile {
             .flat_map(|map| map.iter().map(|(k, v)| (v.as_str(), k.as_str())))
     }
 }
+
+impl Markdown {
+    /// Fill any unset markdown defaults from `other`
+    fn merge_from(&mut self, other: &Markdown) {
+        match (&mut self.blockquote, &other.blockquote) {
+            (Some(mine), Some(theirs)) => mine.merge_from(theirs),
+            (slot @ None, Some(theirs)) => *slot = Some(theirs.clone()),
+            _ => {}
+        }
+        if self.code_block_prefix.is_none() {
+            self.code_block_prefix = other.code_block_prefix.clone();
+        }
+        if self.code_block_focus.is_none() {
+            self.code_block_focus = other.code_block_focus.clone();
+        }
+    }
+}
+
+impl MarkdownBlockQuote {
+    /// Fill any unset blockquote labels from `other`
+    fn merge_from(&mut self, other: &MarkdownBlockQuote) {
+        if self.note.is_none() {
+            self.note = other.note.clone();
+        }
+        if self.tip.is_none() {
+            self.tip = other.tip.clone();
+        }
+        if self.important.is_none() {
+            self.important = other.important.clone();
+        }
+        if self.warning.is_none() {
+            self.warning = other.warning.clone();
+        }
+        if self.caution.is_none() {
+            self.caution = other.caution.clone();
+        }
+    }
+}
+
+/// Merge `other`'s top-level context keys into `mine`, keeping `mine`'s values
+fn merge_context(mine: &mut Value, other: &Value) {
+    if let (Value::Object(mine), Value::Object(other)) = (mine, other) {
+        for (k, v) in other {
+            mine.entry(k.clone()).or_insert_with(|| v.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::yaml::{from_source, YamlSource};
+
+    fn deck(yaml: &str) -> DeckFile {
+        from_source(YamlSource::Resource("test-deck".into()), yaml).expect("deck")
+    }
+
+    fn strings(spanned: &[Spanned<String>]) -> Vec<String> {
+        spanned.iter().map(|s| s.as_str().to_owned()).collect()
+    }
+
+    #[test]
+    fn lists_combine_defaults_first_then_self_deduped() {
+        let mut over = deck("slides: [s2.slides]\nstyles: [b.css, c.css]\n");
+        let base = deck("slides: [s1.slides]\nstyles: [a.css, b.css]\nscripts: [base.js]\n");
+
+        over.merge_from(&base);
+
+        // Base entries first, self appended, de-duplicated by value.
+        assert_eq!(strings(over.styles()), ["a.css", "b.css", "c.css"]);
+        // A list only the base has is taken wholesale.
+        assert_eq!(strings(over.scripts()), ["base.js"]);
+        // Slides belong to the concrete deck and are untouched.
+        assert_eq!(strings(over.slides()), ["s2.slides"]);
+    }
+
+    #[test]
+    fn context_fills_missing_keys_without_clobbering() {
+        let mut over = deck("slides: [s.slides]\ncontext:\n  title: Mine\n");
+        let base = deck("slides: [s.slides]\ncontext:\n  title: Base\n  author: Ada\n");
+
+        over.merge_from(&base);
+
+        let ctx = over.context();
+        assert_eq!(ctx["title"], Value::from("Mine"));
+        assert_eq!(ctx["author"], Value::from("Ada"));
+    }
+}
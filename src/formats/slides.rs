@@ -1,16 +1,22 @@
 //! Slide data formats
 //!
 
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
-use std::{path::Path, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
+use marked_yaml::types::{MarkedMappingNode, MarkedScalarNode};
 use marked_yaml::{LoadError, Node, Spanned};
 use serde::{de::Visitor, Deserialize};
 use thiserror::Error;
 
+use crate::resources;
 use crate::yaml::{self, YamlSource};
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 /// Metadata associated with slides
 ///
@@ -38,11 +44,52 @@ pub struct SlideMetadata {
 }
 
 /// The ratio to use for the slide deck
+#[derive(Clone)]
 pub struct SlideRatio {
     pub width: usize,
     pub height: usize,
 }
 
+impl SlideMetadata {
+    /// Fill any unset metadata from `other`, treating it as the default layer
+    ///
+    /// Optional scalar fields (including `ratio`) keep `self`'s value where set
+    /// and are otherwise taken from `other`.  The `inherit`, `require`, and
+    /// `deny` lists are combined with `other`'s entries first and `self`'s
+    /// appended, de-duplicated by string value keeping the winning entry's span.
+    pub(crate) fn merge_from(&mut self, other: &SlideMetadata) {
+        if self.content_name.is_none() {
+            self.content_name = other.content_name.clone();
+        }
+        if self.content_list.is_none() {
+            self.content_list = other.content_list.clone();
+        }
+        if self.default_template.is_none() {
+            self.default_template = other.default_template.clone();
+        }
+        if self.ratio.is_none() {
+            self.ratio = other.ratio.clone();
+        }
+        merge_spanned_list(&mut self.inherit, &other.inherit);
+        merge_spanned_list(&mut self.require, &other.require);
+        merge_spanned_list(&mut self.deny, &other.deny);
+    }
+}
+
+/// Combine two lists of spanned strings, `other`'s entries first then `mine`'s,
+/// de-duplicated by string value while preserving the winning entry's span
+pub(crate) fn merge_spanned_list(mine: &mut Vec<Spanned<String>>, other: &[Spanned<String>]) {
+    use std::collections::HashSet;
+    let mut seen = HashSet::new();
+    let mut combined = Vec::with_capacity(other.len() + mine.len());
+    for entry in other.iter().chain(mine.iter()) {
+        if seen.insert(entry.as_str().to_string()) {
+            combined.push(entry.clone());
+        }
+    }
+    *mine = combined;
+}
+
 impl<'de> Deserialize<'de> for SlideRatio {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -91,6 +138,18 @@ pub enum SlideLoadError {
     /// The metadata started at the given line number (1-indexed) is bad YAML
     #[error("Bad yaml found at line {0}: {1}")]
     BadMetadata(usize, LoadError),
+    /// A metadata key required by the deck is missing from the slide at the given line
+    #[error("Required metadata `{1}` missing from slide at line {0}")]
+    MissingRequiredMetadata(usize, String),
+    /// A metadata key denied by the deck is present on the slide at the given line
+    #[error("Denied metadata `{1}` present on slide at line {0}")]
+    DeniedMetadata(usize, String),
+    /// An `@include` directive on the given line could not be processed
+    #[error("Failed to include `{1}` (at line {0}): {2}")]
+    IncludeFailed(usize, String, Box<SlideLoadError>),
+    /// An include cycle was detected while following an `@include` directive
+    #[error("Include cycle detected including `{0}`")]
+    IncludeCycle(String),
 }
 
 /// A file of slides
@@ -99,6 +158,7 @@ pub enum SlideLoadError {
 pub struct SlideFile {
     fname: Arc<Path>,
     slides: Vec<SlideContent>,
+    file_meta: Option<Node>,
 }
 
 /// A single slide
@@ -134,6 +194,41 @@ impl SlideContent {
     }
 }
 
+/// A slide whose metadata has been resolved against the deck's [`SlideMetadata`]
+///
+/// Resolution applies inheritance from earlier slides, the deck's
+/// `default-template`, and the `require`/`deny` policy.  The metadata carried
+/// here is always a mapping, even when the original slide had none.
+#[derive(Debug)]
+pub struct ResolvedSlide {
+    meta: MarkedMappingNode,
+    lineno: usize,
+    parts: Vec<String>,
+    notes: String,
+}
+
+impl ResolvedSlide {
+    /// The resolved metadata mapping for this slide
+    pub fn meta(&self) -> &MarkedMappingNode {
+        &self.meta
+    }
+
+    /// The line number on which this slide starts
+    pub fn lineno(&self) -> usize {
+        self.lineno
+    }
+
+    /// The raw parts of this slide
+    pub fn parts(&self) -> &[String] {
+        &self.parts
+    }
+
+    /// The slide notes
+    pub fn notes(&self) -> &str {
+        &self.notes
+    }
+}
+
 impl SlideFile {
     /// The name of the slide file
     pub fn fname(&self) -> Arc<Path> {
@@ -145,21 +240,159 @@ impl SlideFile {
         &self.slides
     }
 
+    /// The deck-level metadata, if the file ends with a trailing metadata block
+    ///
+    /// This is a YAML mapping introduced by a final delimiter after the last
+    /// slide and bound by the end of the file, giving authors a place for
+    /// deck-wide defaults (such as `default-template`, `inherit`, or a per-file
+    /// `context`) colocated with the slides.
+    ///
+    /// Because the block is delimited by end-of-file rather than a closing
+    /// delimiter, a non-empty final metadata block that is never closed is
+    /// *intentionally* treated as file metadata rather than a truncated slide:
+    /// it does not produce [`SlideLoadError::IncompleteMetadata`].  Only a bare
+    /// trailing delimiter with nothing after it is reported as incomplete.
+    pub fn file_meta(&self) -> Option<&Node> {
+        self.file_meta.as_ref()
+    }
+
+    /// Resolve each slide's metadata against the deck-level [`SlideMetadata`]
+    ///
+    /// Slides are walked in order while maintaining a carry-over of the values
+    /// named in `inherit`: each slide starts from its own YAML mapping, any
+    /// inherited key it lacks is filled from the previous slide's resolved
+    /// value, and the carry-over is then updated with this slide's values.  The
+    /// deck's `default-template` is applied when no `template` key is set.
+    ///
+    /// After inheritance we enforce the policy: every key in `require` must be
+    /// present and no key in `deny` may appear.  Violations are collected as
+    /// errors keyed to the offending slide's line number; all slides are
+    /// checked so a single call reports as many problems as possible.
+    pub fn resolve(&self, meta: &SlideMetadata) -> Result<Vec<ResolvedSlide>, Vec<SlideLoadError>> {
+        let mut errs = Vec::new();
+        let mut resolved = Vec::with_capacity(self.slides.len());
+        let mut carry: HashMap<String, Node> = HashMap::new();
+
+        for slide in &self.slides {
+            let mut map = slide.meta.as_mapping().cloned().unwrap_or_default();
+
+            for key in &meta.inherit {
+                let key = key.as_str();
+                if !map.contains_key(key) {
+                    if let Some(value) = carry.get(key) {
+                        map.insert(MarkedScalarNode::from(key), value.clone());
+                    }
+                }
+            }
+
+            for key in &meta.inherit {
+                let key = key.as_str();
+                if let Some(value) = map.get(key) {
+                    carry.insert(key.to_string(), value.clone());
+                }
+            }
+
+            if let Some(default) = &meta.default_template {
+                if !map.contains_key("template") {
+                    map.insert(
+                        MarkedScalarNode::from("template"),
+                        Node::from(MarkedScalarNode::from(default.as_str())),
+                    );
+                }
+            }
+
+            for key in &meta.require {
+                if !map.contains_key(key.as_str()) {
+                    errs.push(SlideLoadError::MissingRequiredMetadata(
+                        slide.lineno,
+                        key.as_str().to_string(),
+                    ));
+                }
+            }
+
+            for key in &meta.deny {
+                if map.contains_key(key.as_str()) {
+                    errs.push(SlideLoadError::DeniedMetadata(
+                        slide.lineno,
+                        key.as_str().to_string(),
+                    ));
+                }
+            }
+
+            resolved.push(ResolvedSlide {
+                meta: map,
+                lineno: slide.lineno,
+                parts: slide.parts.clone(),
+                notes: slide.notes.clone(),
+            });
+        }
+
+        if errs.is_empty() {
+            Ok(resolved)
+        } else {
+            Err(errs)
+        }
+    }
+
     /// Load a slide file from disk and parse it into memory
     ///
     /// The loading is done as "kindly" as possible, but if something
     /// is very broken then we refuse to continue.  Our return value
     /// is either the loaded slide file, or as many errors as we can
     /// usefully report.
+    ///
+    /// Slide files may pull in other slide files with an `@include
+    /// path/to/other.slides` directive on its own line; see
+    /// [`load_inner`](Self::load_inner) for the details.
     pub fn load(path: impl AsRef<Path>) -> Result<Self, Vec<SlideLoadError>> {
-        let mut errs = Vec::new();
         let fname: Arc<Path> = path.as_ref().into();
-        let mut ret = SlideFile {
-            fname,
-            slides: Vec::new(),
-        };
+        let text = std::fs::read_to_string(fname.as_ref()).map_err(|e| vec![e.into()])?;
+
+        let mut slides = Vec::new();
+        let mut errs = Vec::new();
+        let mut visited = HashSet::new();
+        let mut file_meta = None;
+        Self::load_inner(&fname, &text, &mut visited, &mut slides, &mut file_meta, &mut errs);
+
+        if errs.is_empty() {
+            Ok(SlideFile {
+                fname,
+                slides,
+                file_meta,
+            })
+        } else {
+            Err(errs)
+        }
+    }
 
-        let text = std::fs::read_to_string(ret.fname.as_ref()).map_err(|e| vec![e.into()])?;
+    /// Parse the text of a single slide file into `slides`
+    ///
+    /// This is the recursive heart of [`load`](Self::load).  `fname` names the
+    /// file the `text` came from (used both for error provenance and to resolve
+    /// `@include` directives relative to it), `visited` is the set of files
+    /// currently on the include stack (so cycles can be rejected), and any
+    /// problems are appended to `errs`.
+    ///
+    /// An `@include other.slides` line encountered while capturing a slide
+    /// resolves `other.slides` relative to `fname`, loads it recursively, and
+    /// splices its slides into `slides` at that point.
+    ///
+    /// A final delimiter whose metadata block is bound by the end of the file,
+    /// rather than closed by a matching delimiter and followed by slide
+    /// content, is parsed as the deck-level `file_meta` rather than as a slide.
+    fn load_inner(
+        fname: &Arc<Path>,
+        text: &str,
+        visited: &mut HashSet<PathBuf>,
+        slides: &mut Vec<SlideContent>,
+        file_meta: &mut Option<Node>,
+        errs: &mut Vec<SlideLoadError>,
+    ) {
+        let key = fname.to_path_buf();
+        if !visited.insert(key.clone()) {
+            errs.push(SlideLoadError::IncludeCycle(fname.display().to_string()));
+            return;
+        }
 
         enum ParseMode {
             Initial,
@@ -185,8 +418,13 @@ impl SlideFile {
                 }
                 Metadata(ofs, delim, mut raw_meta) => {
                     if line == delim {
+                        // The offset is chosen so that a span's 1-based line
+                        // within the extracted block plus the offset equals the
+                        // absolute file line: the first content line sits at
+                        // `ofs + 2` (the opening delimiter is `ofs + 1`), and a
+                        // span there reports line 1, so the offset is `ofs + 1`.
                         let source =
-                            YamlSource::Slide(ret.fname(), ret.slides.len() + 1, raw_lineofs);
+                            YamlSource::Slide(Arc::clone(fname), slides.len() + 1, ofs + 1);
                         match yaml::node_from_source(source, &raw_meta) {
                             Ok(node) => CapturingSlide(SlideContent {
                                 meta: node,
@@ -212,12 +450,37 @@ impl SlideFile {
                     } else if line == "???" {
                         CapturingNotes(slide)
                     } else if line.chars().all(|c| c == '-') {
-                        ret.slides.push(slide);
+                        slides.push(slide);
                         if line.len() > 3 {
                             Metadata(raw_lineofs, "...", String::new())
                         } else {
                             Metadata(raw_lineofs, "", String::new())
                         }
+                    } else if let Some(target) = line.strip_prefix("@include ") {
+                        // Flush what we have captured so far before splicing, so
+                        // the included slides land after this slide's existing
+                        // content rather than in front of it.  If nothing has
+                        // been captured yet (a standalone include) there is no
+                        // partial slide to split, so we keep capturing on it.
+                        let captured =
+                            !slide.notes.is_empty() || slide.parts.iter().any(|p| !p.is_empty());
+                        if captured {
+                            let meta = slide.meta.clone();
+                            let lineno = slide.lineno;
+                            slides.push(slide);
+                            Self::include(fname, target.trim(), raw_lineofs + 1, visited, slides, errs);
+                            // The remainder of the block continues as a fresh
+                            // slide sharing this slide's metadata.
+                            CapturingSlide(SlideContent {
+                                meta,
+                                lineno,
+                                parts: vec![String::new()],
+                                notes: String::new(),
+                            })
+                        } else {
+                            Self::include(fname, target.trim(), raw_lineofs + 1, visited, slides, errs);
+                            CapturingSlide(slide)
+                        }
                     } else {
                         // Unwrap is fine since there's always at least one part
                         writeln!(slide.parts.last_mut().unwrap(), "{}", line)
@@ -227,7 +490,7 @@ impl SlideFile {
                 }
                 CapturingNotes(mut slide) => {
                     if line.chars().all(|c| c == '-') {
-                        ret.slides.push(slide);
+                        slides.push(slide);
                         if line.len() > 3 {
                             Metadata(raw_lineofs, "...", String::new())
                         } else {
@@ -254,17 +517,274 @@ impl SlideFile {
 
         match mode {
             Initial => errs.push(SlideLoadError::MissingInitialDelimiter),
-            Metadata(ofs, _, _) => errs.push(SlideLoadError::IncompleteMetadata(ofs + 1)),
+            Metadata(ofs, _, raw_meta) => {
+                // A delimiter whose metadata runs to the end of the file is the
+                // deck-level trailing metadata block, not a truncated slide.  An
+                // empty block (a bare trailing delimiter) carries no defaults.
+                if raw_meta.trim().is_empty() {
+                    errs.push(SlideLoadError::IncompleteMetadata(ofs + 1));
+                } else {
+                    // Same offset convention as per-slide metadata above, so a
+                    // span in the trailing block resolves to its absolute line.
+                    let source = YamlSource::Slide(Arc::clone(fname), slides.len() + 1, ofs + 1);
+                    match yaml::node_from_source(source, &raw_meta) {
+                        Ok(node) => *file_meta = Some(node),
+                        Err(e) => errs.push(SlideLoadError::BadMetadata(ofs + 1, e)),
+                    }
+                }
+            }
             CapturingSlide(slide) | CapturingNotes(slide) => {
-                ret.slides.push(slide);
+                slides.push(slide);
             }
             Aborting => {}
         }
 
-        if errs.is_empty() {
-            Ok(ret)
-        } else {
-            Err(errs)
+        visited.remove(&key);
+    }
+
+    /// Resolve and splice an `@include` directive
+    ///
+    /// `rel` is resolved relative to the including file `fname`.  The resolved
+    /// target is read from disk the same way [`load`](Self::load) reads its
+    /// input; only when it is not present on disk do we fall back to the
+    /// embedded [`resources`] so genuinely built-in slide files still work.
+    /// Any errors from the nested load are wrapped with the including
+    /// directive's line number so they still point at a real location.
+    fn include(
+        fname: &Arc<Path>,
+        rel: &str,
+        lineno: usize,
+        visited: &mut HashSet<PathBuf>,
+        slides: &mut Vec<SlideContent>,
+        errs: &mut Vec<SlideLoadError>,
+    ) {
+        let target = fname
+            .parent()
+            .map(|dir| dir.join(rel))
+            .unwrap_or_else(|| PathBuf::from(rel));
+
+        let (incname, text) = match read_slide_source(&target) {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                errs.push(SlideLoadError::IncludeFailed(
+                    lineno,
+                    target.display().to_string(),
+                    Box::new(e),
+                ));
+                return;
+            }
+        };
+
+        let mut nested = Vec::new();
+        // Trailing deck-level metadata belongs to the file `load` was called
+        // on; an included file's own trailing block is not hoisted.
+        let mut nested_meta = None;
+        Self::load_inner(&incname, &text, visited, slides, &mut nested_meta, &mut nested);
+        let name = incname.display().to_string();
+        for e in nested {
+            errs.push(SlideLoadError::IncludeFailed(lineno, name.clone(), Box::new(e)));
         }
     }
 }
+
+/// Read the text of a slide source, preferring disk and falling back to embedded
+///
+/// This mirrors [`SlideFile::load`]'s own `std::fs::read_to_string`, resorting
+/// to [`resources::get`] only when the path is genuinely absent from disk.
+fn read_slide_source(target: &Path) -> Result<(Arc<Path>, String), SlideLoadError> {
+    match std::fs::read_to_string(target) {
+        Ok(text) => Ok((Arc::from(target), text)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let (disk, content) = resources::get(&target.display().to_string())?;
+            let incname: Arc<Path> = disk.map(Arc::from).unwrap_or_else(|| Arc::from(target));
+            let text = std::str::from_utf8(&content)
+                .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?
+                .to_owned();
+            Ok((incname, text))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A fresh, unique temporary directory for slide-file fixtures
+#[cfg(test)]
+fn temp_slide_dir() -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("harvey-slides-{}-{}", std::process::id(), n));
+    std::fs::create_dir_all(&dir).expect("create temp dir");
+    dir
+}
+
+#[cfg(test)]
+fn scalar<'a>(map: &'a MarkedMappingNode, key: &str) -> Option<&'a str> {
+    map.get(key).and_then(|n| n.as_scalar()).map(|s| s.as_str())
+}
+
+#[cfg(test)]
+mod resolve_tests {
+    use super::*;
+
+    fn meta_from(yaml: &str) -> SlideMetadata {
+        crate::yaml::from_source(YamlSource::Resource("test-meta".into()), yaml).expect("meta")
+    }
+
+    fn load_str(body: &str) -> SlideFile {
+        let dir = temp_slide_dir();
+        let path = dir.join("deck.slides");
+        std::fs::write(&path, body).expect("write");
+        SlideFile::load(&path).expect("load")
+    }
+
+    #[test]
+    fn inherits_absent_keys_from_previous_slide() {
+        let sf = load_str("---\ntitle: One\nauthor: Ada\n\nOne\n---\ntitle: Two\n\nTwo\n");
+        let meta = meta_from("inherit:\n  - author\n  - title\n");
+        let resolved = sf.resolve(&meta).expect("resolve");
+        assert_eq!(scalar(resolved[1].meta(), "author"), Some("Ada"));
+        // The slide's own key still wins over the inherited one.
+        assert_eq!(scalar(resolved[1].meta(), "title"), Some("Two"));
+    }
+
+    #[test]
+    fn require_reports_missing_key() {
+        let sf = load_str("---\ntitle: One\n\nOne\n---\nsubtitle: Two\n\nTwo\n");
+        let meta = meta_from("require:\n  - title\n");
+        let errs = sf.resolve(&meta).expect_err("should fail");
+        assert!(errs
+            .iter()
+            .any(|e| matches!(e, SlideLoadError::MissingRequiredMetadata(_, k) if k == "title")));
+    }
+
+    #[test]
+    fn deny_reports_forbidden_key() {
+        let sf = load_str("---\ntitle: One\nsecret: x\n\nOne\n");
+        let meta = meta_from("deny:\n  - secret\n");
+        let errs = sf.resolve(&meta).expect_err("should fail");
+        assert!(errs
+            .iter()
+            .any(|e| matches!(e, SlideLoadError::DeniedMetadata(_, k) if k == "secret")));
+    }
+
+    #[test]
+    fn default_template_applied_when_absent() {
+        let sf = load_str("---\ntitle: One\n\nOne\n");
+        let meta = meta_from("default-template: base\n");
+        let resolved = sf.resolve(&meta).expect("resolve");
+        assert_eq!(scalar(resolved[0].meta(), "template"), Some("base"));
+    }
+}
+
+#[cfg(test)]
+mod span_tests {
+    use super::*;
+
+    #[test]
+    fn resolve_span_reports_absolute_file_lines() {
+        // Line 1 is the `---`, so `title` is file line 2 and `author` line 3.
+        let dir = temp_slide_dir();
+        let path = dir.join("deck.slides");
+        std::fs::write(&path, "---\ntitle: One\nauthor: Ada\n\nBody\n").expect("write");
+        let sf = SlideFile::load(&path).expect("load");
+
+        let map = sf.slides()[0].meta_raw().as_mapping().expect("mapping");
+
+        let title = map.get("title").expect("title");
+        let loc = crate::yaml::resolve_span(title.span()).expect("resolved");
+        assert_eq!(loc.line, 2);
+        // "title: One" — the value begins in column 8 (1-indexed).
+        assert_eq!(loc.column, 8);
+
+        let author = map.get("author").expect("author");
+        let loc = crate::yaml::resolve_span(author.span()).expect("resolved");
+        assert_eq!(loc.line, 3);
+    }
+}
+
+#[cfg(test)]
+mod include_tests {
+    use super::*;
+
+    fn title(sf: &SlideFile, idx: usize) -> Option<String> {
+        sf.slides()[idx]
+            .meta_raw()
+            .as_mapping()
+            .and_then(|m| scalar(m, "title").map(str::to_owned))
+    }
+
+    #[test]
+    fn include_resolves_sibling_on_disk_and_preserves_order() {
+        let dir = temp_slide_dir();
+        std::fs::write(
+            dir.join("part.slides"),
+            "---\ntitle: Part\n\nPart body\n",
+        )
+        .expect("write part");
+        let main = dir.join("main.slides");
+        std::fs::write(
+            &main,
+            "---\ntitle: Main\n\nBefore\n@include part.slides\nAfter\n",
+        )
+        .expect("write main");
+
+        let sf = SlideFile::load(&main).expect("load");
+
+        // The in-progress slide is flushed before the include is spliced, and
+        // capturing resumes afterwards: Main(before), Part, Main(after).
+        assert_eq!(sf.slides().len(), 3);
+        assert_eq!(title(&sf, 0).as_deref(), Some("Main"));
+        assert_eq!(title(&sf, 1).as_deref(), Some("Part"));
+        assert_eq!(title(&sf, 2).as_deref(), Some("Main"));
+        assert!(sf.slides()[0].parts().iter().any(|p| p.contains("Before")));
+        assert!(sf.slides()[2].parts().iter().any(|p| p.contains("After")));
+    }
+
+    #[test]
+    fn include_cycle_is_rejected() {
+        let dir = temp_slide_dir();
+        std::fs::write(dir.join("a.slides"), "---\ntitle: A\n\n@include b.slides\n")
+            .expect("write a");
+        std::fs::write(dir.join("b.slides"), "---\ntitle: B\n\n@include a.slides\n")
+            .expect("write b");
+
+        let errs = SlideFile::load(dir.join("a.slides")).expect_err("cycle should fail");
+        assert!(
+            errs.iter().any(|e| e.to_string().contains("Include cycle")),
+            "expected a cycle error, got: {errs:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod trailing_metadata_tests {
+    use super::*;
+
+    fn load_str(body: &str) -> Result<SlideFile, Vec<SlideLoadError>> {
+        let dir = temp_slide_dir();
+        let path = dir.join("deck.slides");
+        std::fs::write(&path, body).expect("write");
+        SlideFile::load(&path)
+    }
+
+    #[test]
+    fn unterminated_trailing_block_is_file_metadata() {
+        // The final block is bound by EOF (no closing delimiter); it is the
+        // deck-level metadata, not a truncated slide, and does not error.
+        let sf = load_str("---\ntitle: One\n\nBody\n---\ndefault-template: base\n").expect("load");
+        assert_eq!(sf.slides().len(), 1);
+        let meta = sf.file_meta().expect("file meta").as_mapping().expect("mapping");
+        assert_eq!(scalar(meta, "default-template"), Some("base"));
+    }
+
+    #[test]
+    fn bare_trailing_delimiter_is_incomplete() {
+        // An empty trailing block carries no defaults and is still an error.
+        let errs = load_str("---\ntitle: One\n\nBody\n---\n").expect_err("should fail");
+        assert!(errs
+            .iter()
+            .any(|e| matches!(e, SlideLoadError::IncompleteMetadata(_))));
+    }
+}
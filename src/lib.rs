@@ -7,6 +7,7 @@
 // Expect everyting to be documented
 #![deny(missing_docs)]
 
+pub mod diagnostics;
 pub mod formats;
 pub mod resources;
 pub mod yaml;
@@ -11,6 +11,7 @@ use std::{
 
 use marked_yaml::{
     from_yaml_with_options, parse_yaml_with_options, FromYamlError, LoadError, LoaderOptions, Node,
+    Span,
 };
 use serde::de::DeserializeOwned;
 use thiserror::Error;
@@ -103,6 +104,67 @@ where
     Ok(ret)
 }
 
+/// The origin of a resolved YAML span
+///
+/// This mirrors the interesting part of [`YamlSource`] but in a form that the
+/// diagnostics renderer can read the original bytes back from.
+pub enum ResolvedOrigin {
+    /// A file loaded directly from disk
+    File(PathBuf),
+    /// A built-in embedded resource, by name
+    Resource(String),
+    /// A slide file; the path lets the snippet be read back from disk
+    Slide(Arc<Path>),
+}
+
+impl std::fmt::Display for ResolvedOrigin {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ResolvedOrigin::File(path) => write!(f, "{}", path.display()),
+            ResolvedOrigin::Slide(path) => write!(f, "{}", path.display()),
+            ResolvedOrigin::Resource(name) => f.write_str(name),
+        }
+    }
+}
+
+/// A source location resolved from a [`Span`] via the global source table
+pub struct ResolvedLocation {
+    /// Where the YAML originated
+    pub origin: ResolvedOrigin,
+    /// The 1-indexed line within the origin file
+    pub line: usize,
+    /// The 1-indexed column within the line
+    pub column: usize,
+}
+
+impl std::fmt::Display for ResolvedLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.origin, self.line, self.column)
+    }
+}
+
+/// Resolve a [`Span`] to a human-facing location via the global [`SOURCES`]
+///
+/// The span's source id is looked up in the source table.  For slide-embedded
+/// YAML the [`YamlSource::Slide`] line offset is added, so the returned line is
+/// the absolute line within the slide file rather than within the extracted
+/// metadata block.  Returns `None` if the span has no start marker or its
+/// source id is unknown.
+pub fn resolve_span(span: &Span) -> Option<ResolvedLocation> {
+    let start = span.start()?;
+    let sources = sources();
+    let (origin, lineoffset) = match sources.get(start.source())? {
+        YamlSource::DiskFile(path) => (ResolvedOrigin::File(path.clone()), 0),
+        YamlSource::Resource(name) => (ResolvedOrigin::Resource(name.clone()), 0),
+        YamlSource::Slide(path, _, offset) => (ResolvedOrigin::Slide(Arc::clone(path)), *offset),
+    };
+    Some(ResolvedLocation {
+        origin,
+        line: start.line() + lineoffset,
+        column: start.column(),
+    })
+}
+
 /// Load some YAML from a named source, without deserialising
 pub fn node_from_source(source: YamlSource, content: &str) -> Result<Node, LoadError> {
     let options = LoaderOptions {